@@ -1,6 +1,9 @@
 use super::*;
 use geo_booleanop::boolean::{BooleanOp, Float};
 use geo_types::CoordFloat;
+use num_traits::ToPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// If offset computing fails this error is returned.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -12,6 +15,88 @@ pub enum OffsetError {
 /// Arcs around corners are made of 5 segments by default.
 pub const DEFAULT_ARC_SEGMENTS: u32 = 5;
 
+/// How two consecutive offset edges are joined at a corner.
+///
+/// This only affects corners that are convex with respect to the offset
+/// side being drawn; concave corners need no join piece of their own, since
+/// the two segment quads they connect already overlap there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle<F> {
+    /// Round the corner with an arc, same as the historical behavior.
+    Round,
+    /// Connect the two offset edges directly with a straight segment.
+    Bevel,
+    /// Extend both offset edges until they meet. If the distance from the
+    /// corner to that intersection divided by `distance` would exceed the
+    /// given miter limit, falls back to `Bevel`.
+    Miter(F),
+}
+
+impl<F> Default for JoinStyle<F> {
+    fn default() -> Self {
+        JoinStyle::Round
+    }
+}
+
+/// How the two free ends of an open `LineString` offset are finished.
+///
+/// Closed rings (including `Polygon` exteriors/interiors) never have free
+/// ends, so this has no effect on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    /// Wrap the end in a round arc, same as the historical behavior.
+    Round,
+    /// Connect the two offset edges with a straight segment flush with the
+    /// endpoint.
+    Butt,
+    /// Like `Butt`, but the offset edges are first extended past the
+    /// endpoint by `distance`, producing a rectangular stub.
+    Square,
+}
+
+impl Default for CapStyle {
+    fn default() -> Self {
+        CapStyle::Round
+    }
+}
+
+/// Which side of a `LineString`'s travel direction
+/// [`PolylineOffset::offset_polyline`] shifts it towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// To the left of the direction of travel.
+    Left,
+    /// To the right of the direction of travel.
+    Right,
+}
+
+/// Options controlling how an offset is computed, for use with
+/// [`Offset::offset_with_options`].
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetOptions<F> {
+    /// Number of segments used to tessellate a full round arc.
+    pub arc_segments: u32,
+    /// Join style applied to convex corners of `Line`/`LineString`/`Polygon` offsets.
+    pub join_style: JoinStyle<F>,
+    /// Cap style applied to the two free ends of an open `LineString` offset.
+    pub cap_style: CapStyle,
+    /// When set, the number of segments used for any given arc is chosen
+    /// from this maximum chord error instead of from `arc_segments`. See
+    /// [`Offset::offset_with_tolerance`].
+    pub tolerance: Option<F>,
+}
+
+impl<F: CoordFloat> Default for OffsetOptions<F> {
+    fn default() -> Self {
+        OffsetOptions {
+            arc_segments: DEFAULT_ARC_SEGMENTS,
+            join_style: JoinStyle::default(),
+            cap_style: CapStyle::default(),
+            tolerance: None,
+        }
+    }
+}
+
 pub trait Offset<F: CoordFloat + Float> {
     fn offset(&self, distance: F) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
         self.offset_with_arc_segments(distance, DEFAULT_ARC_SEGMENTS)
@@ -22,6 +107,42 @@ pub trait Offset<F: CoordFloat + Float> {
         distance: F,
         arc_segments: u32,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError>;
+
+    /// Like [`Offset::offset_with_arc_segments`] but with full control over
+    /// corner joins (and, via [`OffsetOptions`], other tessellation choices).
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError>;
+
+    /// Offsets with an adaptive arc segment count: each arc gets just enough
+    /// segments to keep the chord deviation from the true arc below
+    /// `max_error`, instead of a fixed segment count for every arc.
+    fn offset_with_tolerance(
+        &self,
+        distance: F,
+        max_error: F,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        self.offset_with_options(
+            distance,
+            OffsetOptions {
+                tolerance: Some(max_error),
+                ..OffsetOptions::default()
+            },
+        )
+    }
+}
+
+/// Shifts a `LineString` to one side, producing another open `LineString`
+/// rather than a closed buffer. Useful for deriving lane edges from a
+/// centerline, where a two-sided [`Offset`] buffer isn't the right shape.
+pub trait PolylineOffset<F: CoordFloat> {
+    fn offset_polyline(
+        &self,
+        distance: F,
+        side: Side,
+    ) -> Result<geo_types::LineString<F>, OffsetError>;
 }
 
 impl<F: EnrichedFloat> Offset<F> for geo_types::GeometryCollection<F> {
@@ -30,13 +151,41 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::GeometryCollection<F> {
         distance: F,
         arc_segments: u32,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        let mut geometry_collection_with_offset = geo_types::MultiPolygon(Vec::new());
-        for geometry in self.0.iter() {
-            let geometry_with_offset = geometry.offset_with_arc_segments(distance, arc_segments)?;
-            geometry_collection_with_offset =
-                geometry_collection_with_offset.union(&geometry_with_offset);
-        }
-        Ok(geometry_collection_with_offset)
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|geometry| geometry.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|geometry| geometry.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
+    }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|geometry| geometry.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|geometry| geometry.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
     }
 }
 
@@ -79,6 +228,39 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::Geometry<F> {
             }
         }
     }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        match self {
+            geo_types::Geometry::Point(point) => point.offset_with_options(distance, options),
+            geo_types::Geometry::Line(line) => line.offset_with_options(distance, options),
+            geo_types::Geometry::LineString(line_tring) => {
+                line_tring.offset_with_options(distance, options)
+            }
+            geo_types::Geometry::Triangle(triangle) => {
+                triangle.to_polygon().offset_with_options(distance, options)
+            }
+            geo_types::Geometry::Rect(rect) => {
+                rect.to_polygon().offset_with_options(distance, options)
+            }
+            geo_types::Geometry::Polygon(polygon) => polygon.offset_with_options(distance, options),
+            geo_types::Geometry::MultiPoint(multi_point) => {
+                multi_point.offset_with_options(distance, options)
+            }
+            geo_types::Geometry::MultiLineString(multi_line_string) => {
+                multi_line_string.offset_with_options(distance, options)
+            }
+            geo_types::Geometry::MultiPolygon(multi_polygon) => {
+                multi_polygon.offset_with_options(distance, options)
+            }
+            geo_types::Geometry::GeometryCollection(geometry_collection) => {
+                geometry_collection.offset_with_options(distance, options)
+            }
+        }
+    }
 }
 
 impl<F: EnrichedFloat> Offset<F> for geo_types::MultiPolygon<F> {
@@ -87,12 +269,41 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::MultiPolygon<F> {
         distance: F,
         arc_segments: u32,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        let mut polygons = geo_types::MultiPolygon(Vec::new());
-        for polygon in self.0.iter() {
-            let polygon_with_offset = polygon.offset_with_arc_segments(distance, arc_segments)?;
-            polygons = polygons.union(&polygon_with_offset);
-        }
-        Ok(polygons)
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|polygon| polygon.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|polygon| polygon.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
+    }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|polygon| polygon.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|polygon| polygon.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
     }
 }
 
@@ -116,6 +327,26 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::Polygon<F> {
                 .difference(&interiors_with_offset)
         })
     }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let exterior_with_offset = self
+            .exterior()
+            .offset_with_options(distance.abs(), options)?;
+        let interiors_with_offset = geo_types::MultiLineString(self.interiors().to_vec())
+            .offset_with_options(distance.abs(), options)?;
+
+        Ok(if distance.is_sign_positive() {
+            self.union(&exterior_with_offset)
+                .union(&interiors_with_offset)
+        } else {
+            self.difference(&exterior_with_offset)
+                .difference(&interiors_with_offset)
+        })
+    }
 }
 
 impl<F: EnrichedFloat> Offset<F> for geo_types::MultiLineString<F> {
@@ -128,14 +359,45 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::MultiLineString<F> {
             return Ok(geo_types::MultiPolygon(Vec::new()));
         }
 
-        let mut multi_line_string_with_offset = geo_types::MultiPolygon(Vec::new());
-        for line_string in self.0.iter() {
-            let line_string_with_offset =
-                line_string.offset_with_arc_segments(distance, arc_segments)?;
-            multi_line_string_with_offset =
-                multi_line_string_with_offset.union(&line_string_with_offset);
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|line_string| line_string.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|line_string| line_string.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
+    }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        if distance < F::zero() {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
         }
-        Ok(multi_line_string_with_offset)
+
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|line_string| line_string.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|line_string| line_string.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
     }
 }
 
@@ -168,6 +430,86 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::LineString<F> {
 
         Ok(line_string_with_offset)
     }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        if distance < F::zero() {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        if self.0.len() < 2 {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        Ok(offset_polygon_for_line_string(&self.0, distance, &options))
+    }
+}
+
+impl<F: CoordFloat> PolylineOffset<F> for geo_types::LineString<F> {
+    fn offset_polyline(
+        &self,
+        distance: F,
+        side: Side,
+    ) -> Result<geo_types::LineString<F>, OffsetError> {
+        if distance < F::zero() || self.0.len() < 2 {
+            return Ok(geo_types::LineString(Vec::new()));
+        }
+
+        let signed_distance = match side {
+            Side::Left => distance,
+            Side::Right => -distance,
+        };
+
+        let offset_segments: Vec<(geo_types::Coord<F>, geo_types::Coord<F>)> = self
+            .lines()
+            .map(|line| {
+                let dx = line.end.x - line.start.x;
+                let dy = line.end.y - line.start.y;
+                let normal = left_normal(dx, dy);
+                (
+                    add_scaled(&line.start, &normal, signed_distance),
+                    add_scaled(&line.end, &normal, signed_distance),
+                )
+            })
+            .collect();
+
+        let mut vertices = Vec::with_capacity(offset_segments.len() + 1);
+        vertices.push(offset_segments[0].0);
+
+        // Reconnecting consecutive offset segments at the intersection of
+        // their supporting lines closes a convex corner's gap cleanly, but
+        // for a sharp concave turn that intersection can land far past the
+        // shared vertex. Clamp it with the same miter-limit-and-fallback
+        // check `JoinStyle::Miter` uses, falling back to a gap-insertion
+        // join (equivalent to `Bevel`) past the limit.
+        let miter_limit = F::from(2.0).unwrap_or_else(F::one);
+        for (i, window) in offset_segments.windows(2).enumerate() {
+            let (prev_start, prev_end) = window[0];
+            let (next_start, next_end) = window[1];
+            let shared_vertex = self.0[i + 1];
+            let joint =
+                line_intersection(&prev_start, &prev_end, &next_start, &next_end).filter(|m| {
+                    let miter_length = ((m.x - shared_vertex.x) * (m.x - shared_vertex.x)
+                        + (m.y - shared_vertex.y) * (m.y - shared_vertex.y))
+                        .sqrt();
+                    distance > F::zero() && miter_length / distance <= miter_limit
+                });
+            match joint {
+                Some(joint) => vertices.push(joint),
+                None => {
+                    vertices.push(prev_end);
+                    vertices.push(next_start);
+                }
+            }
+        }
+
+        vertices.push(offset_segments[offset_segments.len() - 1].1);
+
+        Ok(geo_types::LineString(vertices))
+    }
 }
 
 impl<F: EnrichedFloat> Offset<F> for geo_types::Line<F> {
@@ -215,6 +557,24 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::Line<F> {
             geo_types::Point::from(self.start).offset_with_arc_segments(distance, arc_segments)
         }
     }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        // A single `Line` has no interior vertex for a join style to apply
+        // to; both end caps are exact half-circles (sweep = pi), so the
+        // tolerance-driven segment count can be derived directly.
+        let arc_segments = match options.tolerance {
+            Some(max_error) => {
+                let pi = F::from(std::f64::consts::PI).unwrap_or_else(F::zero);
+                tolerance_segment_count(distance.abs(), pi, max_error)
+            }
+            None => options.arc_segments,
+        };
+        self.offset_with_arc_segments(distance, arc_segments)
+    }
 }
 
 impl<F: EnrichedFloat> Offset<F> for geo_types::MultiPoint<F> {
@@ -227,12 +587,45 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::MultiPoint<F> {
             return Ok(geo_types::MultiPolygon(Vec::new()));
         }
 
-        let mut multi_point_with_offset = geo_types::MultiPolygon(Vec::new());
-        for point in self.0.iter() {
-            let point_with_offset = point.offset_with_arc_segments(distance, arc_segments)?;
-            multi_point_with_offset = multi_point_with_offset.union(&point_with_offset);
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|point| point.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|point| point.offset_with_arc_segments(distance, arc_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
+    }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        if distance < F::zero() {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
         }
-        Ok(multi_point_with_offset)
+
+        #[cfg(feature = "rayon")]
+        let offsets = self
+            .0
+            .par_iter()
+            .map(|point| point.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "rayon"))]
+        let offsets = self
+            .0
+            .iter()
+            .map(|point| point.offset_with_options(distance, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tree_union(offsets))
     }
 }
 
@@ -269,6 +662,412 @@ impl<F: EnrichedFloat> Offset<F> for geo_types::Point<F> {
             Vec::new(),
         )]))
     }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        options: OffsetOptions<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        // A point has no corners to join, only a full circle to tessellate.
+        // `offset_with_arc_segments` draws that circle with
+        // `2 * arc_segments + 1` segments, so halve the (odd) count computed
+        // for the full sweep to draw exactly that many.
+        let arc_segments = match options.tolerance {
+            Some(max_error) => {
+                let pi2 = F::from(std::f64::consts::PI * 2.0).unwrap_or_else(F::zero);
+                (tolerance_segment_count(distance, pi2, max_error) / 2).max(1)
+            }
+            None => options.arc_segments,
+        };
+        self.offset_with_arc_segments(distance, arc_segments)
+    }
+}
+
+/// Unions `polygons` with a balanced binary-tree reduction instead of a left
+/// fold: each round unions adjacent pairs, halving the list, until a single
+/// result remains. Every `union` call then merges two similarly-sized
+/// operands instead of one growing accumulator against the next single
+/// element, which is what kept the naive fold quadratic in `polygons.len()`.
+/// With the `rayon` feature enabled, both the pairing within a round and the
+/// per-element offsets that build `polygons` run in parallel.
+fn tree_union<F: Float>(
+    mut polygons: Vec<geo_types::MultiPolygon<F>>,
+) -> geo_types::MultiPolygon<F> {
+    while polygons.len() > 1 {
+        #[cfg(feature = "rayon")]
+        let next_round = polygons
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a.union(b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        #[cfg(not(feature = "rayon"))]
+        let next_round = polygons
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a.union(b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+
+        polygons = next_round;
+    }
+    polygons
+        .pop()
+        .unwrap_or_else(|| geo_types::MultiPolygon(Vec::new()))
+}
+
+/// Builds the buffer polygon of an (open or closed) `LineString`'s points as
+/// a union of independently-simple pieces: a flat quad per segment, a join
+/// piece at every convex corner (concave corners are already covered by
+/// their neighboring quads), and caps at the two free ends of an open line.
+///
+/// Unlike tracing one continuous boundary ring, none of these pieces can
+/// self-intersect on their own, so combining them with real `union()` calls
+/// (rather than relying on a boolean cleanup step) always yields a valid
+/// result.
+fn offset_polygon_for_line_string<F: EnrichedFloat>(
+    points: &[geo_types::Coord<F>],
+    distance: F,
+    options: &OffsetOptions<F>,
+) -> geo_types::MultiPolygon<F> {
+    let closed = points.len() > 2 && points[0] == points[points.len() - 1];
+    let vertex_count = if closed {
+        points.len() - 1
+    } else {
+        points.len()
+    };
+    let segment_count = if closed {
+        vertex_count
+    } else {
+        vertex_count - 1
+    };
+
+    let mut result = geo_types::MultiPolygon(Vec::new());
+
+    for i in 0..segment_count {
+        let p = points[i];
+        let q = points[(i + 1) % vertex_count];
+        let quad = segment_quad(&p, &q, distance);
+        result = result.union(&geo_types::MultiPolygon(vec![quad]));
+    }
+
+    let joint_indices: Vec<usize> = if closed {
+        (0..vertex_count).collect()
+    } else {
+        (1..vertex_count - 1).collect()
+    };
+    for i in joint_indices {
+        let prev = points[(i + vertex_count - 1) % vertex_count];
+        let point = points[i];
+        let next = points[(i + 1) % vertex_count];
+        if let Some(join_piece) = convex_join_piece(&prev, &point, &next, distance, options) {
+            result = result.union(&geo_types::MultiPolygon(vec![join_piece]));
+        }
+    }
+
+    if !closed {
+        if let Some(cap) = cap_piece(&points[0], &points[1], distance, options) {
+            result = result.union(&geo_types::MultiPolygon(vec![cap]));
+        }
+        if let Some(cap) = cap_piece(
+            &points[vertex_count - 1],
+            &points[vertex_count - 2],
+            distance,
+            options,
+        ) {
+            result = result.union(&geo_types::MultiPolygon(vec![cap]));
+        }
+    }
+
+    result
+}
+
+/// The flat quad covering one segment's offset, extending `distance` to
+/// either side of the line from `p` to `q`.
+fn segment_quad<F: CoordFloat>(
+    p: &geo_types::Coord<F>,
+    q: &geo_types::Coord<F>,
+    distance: F,
+) -> geo_types::Polygon<F> {
+    let dx = q.x - p.x;
+    let dy = q.y - p.y;
+    let normal = left_normal(dx, dy);
+
+    let vertices = vec![
+        add_scaled(p, &normal, distance),
+        add_scaled(q, &normal, distance),
+        add_scaled(q, &normal, -distance),
+        add_scaled(p, &normal, -distance),
+    ];
+    geo_types::Polygon::new(geo_types::LineString(vertices), vec![])
+}
+
+/// The extra piece needed to fill the gap at a convex corner, applying the
+/// requested `join_style`. Concave corners need no piece of their own: the
+/// two segment quads they connect already overlap there.
+fn convex_join_piece<F: EnrichedFloat>(
+    prev: &geo_types::Coord<F>,
+    point: &geo_types::Coord<F>,
+    next: &geo_types::Coord<F>,
+    distance: F,
+    options: &OffsetOptions<F>,
+) -> Option<geo_types::Polygon<F>> {
+    let dx_in = point.x - prev.x;
+    let dy_in = point.y - prev.y;
+    let dx_out = next.x - point.x;
+    let dy_out = next.y - point.y;
+
+    // A left turn (cross > 0) opens a gap on the right (negative normal)
+    // side: the two segment quads already overlap on the left. A right turn
+    // (cross < 0) is the mirror image. Either way, only one side of any
+    // given vertex ever needs a fill piece; a straight vertex needs neither.
+    let cross = dx_in * dy_out - dy_in * dx_out;
+    let fill_distance = if cross > F::zero() {
+        -distance
+    } else if cross < F::zero() {
+        distance
+    } else {
+        return None;
+    };
+
+    let normal_in = left_normal(dx_in, dy_in);
+    let normal_out = left_normal(dx_out, dy_out);
+
+    let a = add_scaled(point, &normal_in, fill_distance);
+    let b = add_scaled(point, &normal_out, fill_distance);
+
+    let mut vertices = vec![*point];
+    match &options.join_style {
+        JoinStyle::Round => {
+            // `create_arc` sweeps clockwise from its start vertex to its end
+            // vertex; on a left turn the gap runs counter-clockwise from `a`
+            // to `b`, so start the arc from `b` instead.
+            let (start, end) = if cross > F::zero() { (b, a) } else { (a, b) };
+            let segments = arc_segments_for(point, &start, &end, true, distance, options);
+            F::create_arc(&mut vertices, point, distance, &start, &end, segments, true);
+        }
+        JoinStyle::Bevel => {
+            vertices.push(a);
+            vertices.push(b);
+        }
+        JoinStyle::Miter(miter_limit) => {
+            let miter = if distance > F::zero() {
+                line_intersection(
+                    &a,
+                    &geo_types::Coord::from((a.x + dx_in, a.y + dy_in)),
+                    &b,
+                    &geo_types::Coord::from((b.x + dx_out, b.y + dy_out)),
+                )
+                .filter(|m| {
+                    let miter_length = ((m.x - point.x) * (m.x - point.x)
+                        + (m.y - point.y) * (m.y - point.y))
+                        .sqrt();
+                    miter_length / distance <= *miter_limit
+                })
+            } else {
+                None
+            };
+
+            vertices.push(a);
+            if let Some(m) = miter {
+                vertices.push(m);
+            }
+            vertices.push(b);
+        }
+    }
+
+    Some(geo_types::Polygon::new(
+        geo_types::LineString(vertices),
+        vec![],
+    ))
+}
+
+/// The piece finishing a free end of an open line's offset, spanning both
+/// sides of `center`, using the requested [`CapStyle`]. `Butt` needs no
+/// piece: the end segment's quad already ends flush with the endpoint.
+fn cap_piece<F: EnrichedFloat>(
+    center: &geo_types::Coord<F>,
+    neighbor: &geo_types::Coord<F>,
+    distance: F,
+    options: &OffsetOptions<F>,
+) -> Option<geo_types::Polygon<F>> {
+    let dx = center.x - neighbor.x;
+    let dy = center.y - neighbor.y;
+    let normal = left_normal(dx, dy);
+    let a = add_scaled(center, &normal, distance);
+    let b = add_scaled(center, &normal, -distance);
+
+    match options.cap_style {
+        CapStyle::Round => {
+            let mut vertices = vec![*center];
+            let segments = arc_segments_for(center, &a, &b, true, distance, options);
+            F::create_arc(&mut vertices, center, distance, &a, &b, segments, true);
+            Some(geo_types::Polygon::new(
+                geo_types::LineString(vertices),
+                vec![],
+            ))
+        }
+        CapStyle::Butt => None,
+        CapStyle::Square => {
+            let length = (dx * dx + dy * dy).sqrt();
+            let direction = if length == F::zero() {
+                geo_types::Coord::from((F::zero(), F::zero()))
+            } else {
+                geo_types::Coord::from((dx / length, dy / length))
+            };
+            let vertices = vec![
+                a,
+                add_scaled(&a, &direction, distance),
+                add_scaled(&b, &direction, distance),
+                b,
+            ];
+            Some(geo_types::Polygon::new(
+                geo_types::LineString(vertices),
+                vec![],
+            ))
+        }
+    }
+}
+
+/// Unit vector perpendicular to `(dx, dy)`, rotated 90° counter-clockwise
+/// (i.e. to the left of that direction).
+fn left_normal<F: CoordFloat>(dx: F, dy: F) -> geo_types::Coord<F> {
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == F::zero() {
+        return geo_types::Coord::from((F::zero(), F::zero()));
+    }
+    geo_types::Coord::from((-dy / length, dx / length))
+}
+
+fn add_scaled<F: CoordFloat>(
+    point: &geo_types::Coord<F>,
+    normal: &geo_types::Coord<F>,
+    distance: F,
+) -> geo_types::Coord<F> {
+    geo_types::Coord::from((point.x + normal.x * distance, point.y + normal.y * distance))
+}
+
+/// Intersection of line `p1`-`p2` with line `p3`-`p4`, treating both as
+/// infinite lines. Returns `None` if they are parallel.
+fn line_intersection<F: CoordFloat>(
+    p1: &geo_types::Coord<F>,
+    p2: &geo_types::Coord<F>,
+    p3: &geo_types::Coord<F>,
+    p4: &geo_types::Coord<F>,
+) -> Option<geo_types::Coord<F>> {
+    let d1x = p2.x - p1.x;
+    let d1y = p2.y - p1.y;
+    let d2x = p4.x - p3.x;
+    let d2y = p4.y - p3.y;
+
+    let denom = d1x * d2y - d1y * d2x;
+    if denom == F::zero() {
+        return None;
+    }
+
+    let t = ((p3.x - p1.x) * d2y - (p3.y - p1.y) * d2x) / denom;
+    Some(geo_types::Coord::from((p1.x + t * d1x, p1.y + t * d1y)))
+}
+
+/// Number of segments to pass to `create_arc` for the arc from `start_vertex`
+/// to `end_vertex` around `center`: either `options.arc_segments`, or, when
+/// `options.tolerance` is set, the smallest count keeping that specific arc's
+/// chord error under the tolerance.
+fn arc_segments_for<F: CoordFloat>(
+    center: &geo_types::Coord<F>,
+    start_vertex: &geo_types::Coord<F>,
+    end_vertex: &geo_types::Coord<F>,
+    outwards: bool,
+    radius: F,
+    options: &OffsetOptions<F>,
+) -> u32 {
+    match options.tolerance {
+        Some(max_error) => {
+            let sweep = arc_sweep_angle(center, start_vertex, end_vertex, outwards);
+            tolerance_segment_count(radius, sweep, max_error)
+        }
+        None => options.arc_segments,
+    }
+}
+
+/// Sweep angle (radians, in `(0, 2*pi]` when `outwards`, `[0, 2*pi)`
+/// otherwise) that `create_arc` draws between
+/// `start_vertex` and `end_vertex` around `center` for the given `outwards`
+/// flag. Mirrors the angle computation done inside `EnrichedFloat::create_arc`.
+fn arc_sweep_angle<F: CoordFloat>(
+    center: &geo_types::Coord<F>,
+    start_vertex: &geo_types::Coord<F>,
+    end_vertex: &geo_types::Coord<F>,
+    outwards: bool,
+) -> F {
+    let pi2 = F::from(std::f64::consts::PI * 2.0).unwrap_or_else(F::zero);
+
+    let start_angle = (start_vertex.y - center.y).atan2(start_vertex.x - center.x);
+    let start_angle = if start_angle.is_sign_negative() {
+        start_angle + pi2
+    } else {
+        start_angle
+    };
+
+    let end_angle = (end_vertex.y - center.y).atan2(end_vertex.x - center.x);
+    let end_angle = if end_angle.is_sign_negative() {
+        end_angle + pi2
+    } else {
+        end_angle
+    };
+
+    let angle = if start_angle > end_angle {
+        start_angle - end_angle
+    } else {
+        start_angle + pi2 - end_angle
+    };
+
+    if outwards {
+        angle
+    } else {
+        pi2 - angle
+    }
+}
+
+/// Number of segments needed to keep the chord deviation between a true arc
+/// of `radius` sweeping `sweep` radians and its tessellation below `max_error`.
+fn tolerance_segment_count<F: CoordFloat>(radius: F, sweep: F, max_error: F) -> u32 {
+    if radius <= F::zero() || sweep <= F::zero() {
+        return 1;
+    }
+
+    // Clamp max_error to stay strictly below the radius, otherwise
+    // `acos(1 - max_error / radius)` is undefined (or degenerate).
+    let max_error = if max_error < radius {
+        max_error
+    } else {
+        radius / (F::one() + F::one())
+    };
+    if max_error <= F::zero() {
+        return 1;
+    }
+
+    let half_angle = (F::one() - max_error / radius).acos();
+    if half_angle <= F::zero() {
+        return 1;
+    }
+
+    let segments = (sweep / (half_angle + half_angle)).ceil();
+    let segments = segments.to_u32().unwrap_or(1).max(1);
+
+    // `create_arc` silently forces an even segment count down to the next
+    // odd one, which would draw fewer (coarser) segments than just computed
+    // and could push the true chord error back over `max_error`. Round up
+    // instead of down, so the count it actually uses still meets the bound.
+    if segments % 2 == 0 {
+        segments + 1
+    } else {
+        segments
+    }
 }
 
 trait EnrichedFloat: CoordFloat + Float {
@@ -396,3 +1195,610 @@ impl EnrichedFloat for f64 {
         2.0 * std::f64::consts::PI / f64::from(frags)
     }
 }
+
+/// Grows a geometry by the Minkowski sum with a caller-supplied convex
+/// `kernel` polygon, instead of the circular dilation that [`Offset`] computes.
+/// A square kernel yields a square-cornered buffer, a hexagon yields chamfered
+/// corners, and so on.
+pub trait MinkowskiOffset<F: CoordFloat + Float> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError>;
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::GeometryCollection<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        for geometry in self.0.iter() {
+            let geometry_grown = geometry.minkowski_offset(kernel)?;
+            grown = grown.union(&geometry_grown);
+        }
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::Geometry<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        match self {
+            geo_types::Geometry::Point(point) => point.minkowski_offset(kernel),
+            geo_types::Geometry::Line(line) => line.minkowski_offset(kernel),
+            geo_types::Geometry::LineString(line_string) => line_string.minkowski_offset(kernel),
+            geo_types::Geometry::Triangle(triangle) => {
+                triangle.to_polygon().minkowski_offset(kernel)
+            }
+            geo_types::Geometry::Rect(rect) => rect.to_polygon().minkowski_offset(kernel),
+            geo_types::Geometry::Polygon(polygon) => polygon.minkowski_offset(kernel),
+            geo_types::Geometry::MultiPoint(multi_point) => multi_point.minkowski_offset(kernel),
+            geo_types::Geometry::MultiLineString(multi_line_string) => {
+                multi_line_string.minkowski_offset(kernel)
+            }
+            geo_types::Geometry::MultiPolygon(multi_polygon) => {
+                multi_polygon.minkowski_offset(kernel)
+            }
+            geo_types::Geometry::GeometryCollection(geometry_collection) => {
+                geometry_collection.minkowski_offset(kernel)
+            }
+        }
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::MultiPolygon<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        for polygon in self.0.iter() {
+            let polygon_grown = polygon.minkowski_offset(kernel)?;
+            grown = grown.union(&polygon_grown);
+        }
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::Polygon<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let subject = polygon_ring_ccw(self.exterior());
+        let kernel_ring = polygon_ring_ccw(kernel.exterior());
+
+        if subject.len() < 3 || kernel_ring.len() < 3 {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        if convex_hull(&subject).len() == subject.len() {
+            // Convex subject: the angle-merge convolution gives the sum exactly.
+            let summed = minkowski_sum_convex(&subject, &kernel_ring);
+            return Ok(geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+                geo_types::LineString(summed),
+                vec![],
+            )]));
+        }
+
+        // Non-convex subject: union the kernel swept along every edge. That
+        // alone only covers the boundary, so also union in the original
+        // polygon translated to a kernel vertex (which is definitely in the
+        // sum) to fill the interior, plus the polygon itself in case the
+        // kernel contains the origin.
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        let n = subject.len();
+        for i in 0..n {
+            let hull = edge_kernel_sweep(&kernel_ring, &subject[i], &subject[(i + 1) % n]);
+            grown = grown.union(&geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+                geo_types::LineString(hull),
+                vec![],
+            )]));
+        }
+
+        let translated_self = translate_polygon(self, kernel_ring[0].x, kernel_ring[0].y);
+        grown = grown.union(&geo_types::MultiPolygon(vec![translated_self]));
+        if convex_polygon_contains_origin(&kernel_ring) {
+            grown = grown.union(&geo_types::MultiPolygon(vec![self.clone()]));
+        }
+
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::MultiLineString<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        for line_string in self.0.iter() {
+            let line_string_grown = line_string.minkowski_offset(kernel)?;
+            grown = grown.union(&line_string_grown);
+        }
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::LineString<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let kernel_ring = polygon_ring_ccw(kernel.exterior());
+        if kernel_ring.len() < 3 {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        for line in self.lines() {
+            let hull = edge_kernel_sweep(&kernel_ring, &line.start, &line.end);
+            grown = grown.union(&geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+                geo_types::LineString(hull),
+                vec![],
+            )]));
+        }
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::Line<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let kernel_ring = polygon_ring_ccw(kernel.exterior());
+        if kernel_ring.len() < 3 {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        let hull = edge_kernel_sweep(&kernel_ring, &self.start, &self.end);
+        Ok(geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+            geo_types::LineString(hull),
+            vec![],
+        )]))
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::MultiPoint<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let mut grown = geo_types::MultiPolygon(Vec::new());
+        for point in self.0.iter() {
+            let point_grown = point.minkowski_offset(kernel)?;
+            grown = grown.union(&point_grown);
+        }
+        Ok(grown)
+    }
+}
+
+impl<F: EnrichedFloat> MinkowskiOffset<F> for geo_types::Point<F> {
+    fn minkowski_offset(
+        &self,
+        kernel: &geo_types::Polygon<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let kernel_ring = polygon_ring_ccw(kernel.exterior());
+        if kernel_ring.len() < 3 {
+            return Ok(geo_types::MultiPolygon(Vec::new()));
+        }
+
+        let translated = kernel_ring
+            .iter()
+            .map(|vertex| geo_types::Coord::from((vertex.x + self.x(), vertex.y + self.y())))
+            .collect();
+        Ok(geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+            geo_types::LineString(translated),
+            vec![],
+        )]))
+    }
+}
+
+/// Returns `ring`'s points (without the closing duplicate) in counter-clockwise order.
+fn polygon_ring_ccw<F: CoordFloat>(ring: &geo_types::LineString<F>) -> Vec<geo_types::Coord<F>> {
+    let mut points: Vec<_> = ring.0.to_vec();
+    if points.len() > 1 && points[0] == points[points.len() - 1] {
+        points.pop();
+    }
+    if signed_area(&points) < F::zero() {
+        points.reverse();
+    }
+    points
+}
+
+/// Twice the signed area of the (open) ring `points`; negative for
+/// clockwise-wound rings.
+fn signed_area<F: CoordFloat>(points: &[geo_types::Coord<F>]) -> F {
+    let n = points.len();
+    let mut sum = F::zero();
+    for i in 0..n {
+        let p = points[i];
+        let q = points[(i + 1) % n];
+        sum = sum + (p.x * q.y - q.x * p.y);
+    }
+    sum
+}
+
+/// Convex hull of `points`, in counter-clockwise order, via Andrew's monotone chain.
+fn convex_hull<F: CoordFloat>(points: &[geo_types::Coord<F>]) -> Vec<geo_types::Coord<F>> {
+    let mut pts = points.to_vec();
+    pts.sort_by(|p, q| {
+        p.x.partial_cmp(&q.x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| p.y.partial_cmp(&q.y).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    pts.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    fn cross<F: CoordFloat>(
+        o: &geo_types::Coord<F>,
+        a: &geo_types::Coord<F>,
+        b: &geo_types::Coord<F>,
+    ) -> F {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    }
+
+    let mut lower: Vec<geo_types::Coord<F>> = Vec::new();
+    for &p in &pts {
+        while lower.len() >= 2
+            && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], &p) <= F::zero()
+        {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper: Vec<geo_types::Coord<F>> = Vec::new();
+    for &p in pts.iter().rev() {
+        while upper.len() >= 2
+            && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], &p) <= F::zero()
+        {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// Minkowski sum of two convex, counter-clockwise-wound polygons (given as
+/// their open vertex rings), by merging their directed edges in angular
+/// order. This is the standard convex-convex Minkowski sum algorithm.
+fn minkowski_sum_convex<F: CoordFloat>(
+    a: &[geo_types::Coord<F>],
+    b: &[geo_types::Coord<F>],
+) -> Vec<geo_types::Coord<F>> {
+    let na = a.len();
+    let nb = b.len();
+    if na == 0 {
+        return b.to_vec();
+    }
+    if nb == 0 {
+        return a.to_vec();
+    }
+
+    let ai0 = lowest_index(a);
+    let bi0 = lowest_index(b);
+
+    let mut edges: Vec<(F, geo_types::Coord<F>)> = Vec::with_capacity(na + nb);
+    for k in 0..na {
+        let i = (ai0 + k) % na;
+        let j = (ai0 + k + 1) % na;
+        let dx = a[j].x - a[i].x;
+        let dy = a[j].y - a[i].y;
+        edges.push((dy.atan2(dx), geo_types::Coord::from((dx, dy))));
+    }
+    for k in 0..nb {
+        let i = (bi0 + k) % nb;
+        let j = (bi0 + k + 1) % nb;
+        let dx = b[j].x - b[i].x;
+        let dy = b[j].y - b[i].y;
+        edges.push((dy.atan2(dx), geo_types::Coord::from((dx, dy))));
+    }
+    // Walking a CCW convex ring from its lowest vertex, edge angles increase
+    // through [0, 2*pi); shift atan2's negative range up to match that walk.
+    let pi2 = F::from(std::f64::consts::PI * 2.0).unwrap_or_else(F::zero);
+    for edge in edges.iter_mut() {
+        if edge.0 < F::zero() {
+            edge.0 = edge.0 + pi2;
+        }
+    }
+    edges.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut vertices = Vec::with_capacity(edges.len());
+    let mut current = geo_types::Coord::from((a[ai0].x + b[bi0].x, a[ai0].y + b[bi0].y));
+    vertices.push(current);
+    for (_, edge) in edges.iter().take(edges.len() - 1) {
+        current = geo_types::Coord::from((current.x + edge.x, current.y + edge.y));
+        vertices.push(current);
+    }
+    vertices
+}
+
+/// Index of the lowest (then leftmost) point, the canonical starting point
+/// for walking a convex ring's edges in angular order.
+fn lowest_index<F: CoordFloat>(points: &[geo_types::Coord<F>]) -> usize {
+    let mut index = 0;
+    for i in 1..points.len() {
+        if points[i].y < points[index].y
+            || (points[i].y == points[index].y && points[i].x < points[index].x)
+        {
+            index = i;
+        }
+    }
+    index
+}
+
+/// Minkowski sum of the segment `p`-`q` with the convex `kernel_ring`: the
+/// convex hull of the kernel translated to each endpoint.
+fn edge_kernel_sweep<F: CoordFloat>(
+    kernel_ring: &[geo_types::Coord<F>],
+    p: &geo_types::Coord<F>,
+    q: &geo_types::Coord<F>,
+) -> Vec<geo_types::Coord<F>> {
+    let mut points = Vec::with_capacity(kernel_ring.len() * 2);
+    for vertex in kernel_ring {
+        points.push(geo_types::Coord::from((vertex.x + p.x, vertex.y + p.y)));
+        points.push(geo_types::Coord::from((vertex.x + q.x, vertex.y + q.y)));
+    }
+    convex_hull(&points)
+}
+
+/// Whether a CCW convex ring contains the origin, i.e. the origin is on the
+/// left of every edge. Used to check `0 ∈ K` before relying on `P ⊆ P⊕K`.
+fn convex_polygon_contains_origin<F: CoordFloat>(ring: &[geo_types::Coord<F>]) -> bool {
+    let n = ring.len();
+    (0..n).all(|i| {
+        let a = ring[i];
+        let b = ring[(i + 1) % n];
+        let cross = (b.x - a.x) * (F::zero() - a.y) - (b.y - a.y) * (F::zero() - a.x);
+        cross >= F::zero()
+    })
+}
+
+/// Translates every vertex of `polygon` (exterior and interiors) by `(dx, dy)`.
+fn translate_polygon<F: CoordFloat>(
+    polygon: &geo_types::Polygon<F>,
+    dx: F,
+    dy: F,
+) -> geo_types::Polygon<F> {
+    let translate_ring = |ring: &geo_types::LineString<F>| {
+        geo_types::LineString(
+            ring.0
+                .iter()
+                .map(|c| geo_types::Coord::from((c.x + dx, c.y + dy)))
+                .collect(),
+        )
+    };
+    geo_types::Polygon::new(
+        translate_ring(polygon.exterior()),
+        polygon.interiors().iter().map(translate_ring).collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_at(x: f64, y: f64, side: f64) -> geo_types::Polygon<f64> {
+        geo_types::Polygon::new(
+            geo_types::LineString(vec![
+                geo_types::Coord::from((x, y)),
+                geo_types::Coord::from((x + side, y)),
+                geo_types::Coord::from((x + side, y + side)),
+                geo_types::Coord::from((x, y + side)),
+                geo_types::Coord::from((x, y)),
+            ]),
+            vec![],
+        )
+    }
+
+    fn polygon_area(polygon: &geo_types::Polygon<f64>) -> f64 {
+        signed_area(&polygon.exterior().0).abs()
+            - polygon
+                .interiors()
+                .iter()
+                .map(|interior| signed_area(&interior.0).abs())
+                .sum::<f64>()
+    }
+
+    /// Even-odd ray casting, used to check membership independently of
+    /// whichever polygon/boolean machinery produced `ring`.
+    fn ring_contains_point(ring: &geo_types::LineString<f64>, point: (f64, f64)) -> bool {
+        let (px, py) = point;
+        let mut inside = false;
+        let points = &ring.0;
+        let n = points.len();
+        for i in 0..n {
+            let a = points[i];
+            let b = points[(i + 1) % n];
+            if (a.y > py) != (b.y > py) {
+                let x_at_py = a.x + (py - a.y) / (b.y - a.y) * (b.x - a.x);
+                if px < x_at_py {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    fn multi_polygon_contains(multi: &geo_types::MultiPolygon<f64>, point: (f64, f64)) -> bool {
+        multi
+            .0
+            .iter()
+            .any(|polygon| ring_contains_point(polygon.exterior(), point))
+    }
+
+    #[test]
+    fn minkowski_offset_off_origin_kernel_excludes_original_subject() {
+        // Non-convex "L" subject at the origin, and a kernel triangle that
+        // does not contain the origin (it sits entirely near (20, 20)).
+        let subject = geo_types::Polygon::new(
+            geo_types::LineString(vec![
+                geo_types::Coord::from((0.0, 0.0)),
+                geo_types::Coord::from((8.0, 0.0)),
+                geo_types::Coord::from((8.0, 4.0)),
+                geo_types::Coord::from((4.0, 4.0)),
+                geo_types::Coord::from((4.0, 8.0)),
+                geo_types::Coord::from((0.0, 8.0)),
+                geo_types::Coord::from((0.0, 0.0)),
+            ]),
+            vec![],
+        );
+        let kernel = geo_types::Polygon::new(
+            geo_types::LineString(vec![
+                geo_types::Coord::from((20.0, 20.0)),
+                geo_types::Coord::from((21.0, 20.0)),
+                geo_types::Coord::from((20.0, 21.0)),
+                geo_types::Coord::from((20.0, 20.0)),
+            ]),
+            vec![],
+        );
+
+        let sum = subject.minkowski_offset(&kernel).unwrap();
+
+        // A point deep inside the original, untranslated subject must not be
+        // reported as part of `subject ⊕ kernel`: the kernel doesn't contain
+        // the origin, so `subject ⊆ subject ⊕ kernel` does not hold.
+        assert!(!multi_polygon_contains(&sum, (1.0, 1.0)));
+    }
+
+    #[test]
+    fn offset_with_options_square_bevel_area_matches_expected() {
+        let square = square_at(0.0, 0.0, 10.0);
+        let grown = square
+            .offset_with_options(
+                2.0,
+                OffsetOptions {
+                    join_style: JoinStyle::Bevel,
+                    ..OffsetOptions::default()
+                },
+            )
+            .unwrap();
+
+        // A 10x10 square grown by 2 with bevelled corners is a 14x14 square
+        // with its 4 corners (each a 2x2 right triangle, area 2) cut off.
+        let expected_area = 14.0 * 14.0 - 4.0 * 2.0;
+        let area: f64 = grown.0.iter().map(polygon_area).sum();
+        assert!(
+            (area - expected_area).abs() < 1e-6,
+            "area {} != expected {}",
+            area,
+            expected_area
+        );
+    }
+
+    #[test]
+    fn offset_with_options_square_miter_area_matches_expected() {
+        let square = square_at(0.0, 0.0, 10.0);
+        let grown = square
+            .offset_with_options(
+                2.0,
+                OffsetOptions {
+                    join_style: JoinStyle::Miter(10.0),
+                    ..OffsetOptions::default()
+                },
+            )
+            .unwrap();
+
+        // With a generous miter limit every corner stays sharp, giving the
+        // full 14x14 square.
+        let expected_area = 14.0 * 14.0;
+        let area: f64 = grown.0.iter().map(polygon_area).sum();
+        assert!(
+            (area - expected_area).abs() < 1e-6,
+            "area {} != expected {}",
+            area,
+            expected_area
+        );
+    }
+
+    #[test]
+    fn tree_union_matches_sum_of_disjoint_areas() {
+        let offsets: Vec<geo_types::MultiPolygon<f64>> = (0..5)
+            .map(|i| geo_types::MultiPolygon(vec![square_at(i as f64 * 100.0, 0.0, 10.0)]))
+            .collect();
+        let expected_area: f64 = offsets
+            .iter()
+            .flat_map(|multi| multi.0.iter())
+            .map(polygon_area)
+            .sum();
+
+        let unioned = tree_union(offsets);
+
+        let area: f64 = unioned.0.iter().map(polygon_area).sum();
+        assert!(
+            (area - expected_area).abs() < 1e-6,
+            "area {} != expected {}",
+            area,
+            expected_area
+        );
+    }
+
+    #[test]
+    fn tolerance_segment_count_stays_within_max_error_after_create_arcs_odd_rounding() {
+        // A half-circle (the sweep a cap/line-end draws) of radius 10 with
+        // max_error 1 computes a raw minimal count of 4, which `create_arc`
+        // would otherwise silently round down to 3 (forcing it odd),
+        // pushing the true chord error to 10*(1-cos(pi/6)) ~= 1.34 - over
+        // the requested bound.
+        let radius = 10.0_f64;
+        let sweep = std::f64::consts::PI;
+        let max_error = 1.0_f64;
+
+        let segments = tolerance_segment_count(radius, sweep, max_error);
+        assert_eq!(segments % 2, 1, "segments {} is not odd", segments);
+
+        let half_angle = sweep / (2.0 * segments as f64);
+        let true_chord_error = radius * (1.0 - half_angle.cos());
+        assert!(
+            true_chord_error <= max_error,
+            "chord error {} exceeds max_error {}",
+            true_chord_error,
+            max_error
+        );
+    }
+
+    #[test]
+    fn offset_with_options_open_polyline_interior_join_matches_expected() {
+        // A single interior turn, offset with a Bevel join and Butt caps (so
+        // the only piece beyond the two segment quads is the interior join).
+        let polyline = geo_types::LineString(vec![
+            geo_types::Coord::from((0.0, 0.0)),
+            geo_types::Coord::from((10.0, 0.0)),
+            geo_types::Coord::from((10.0, 10.0)),
+        ]);
+        let grown = polyline
+            .offset_with_options(
+                1.0,
+                OffsetOptions {
+                    join_style: JoinStyle::Bevel,
+                    cap_style: CapStyle::Butt,
+                    ..OffsetOptions::default()
+                },
+            )
+            .unwrap();
+
+        // Two 10x2 segment quads (area 20 each), overlapping on a 1x1 square
+        // near the corner, plus the 1x1/2 bevel triangle filling the outer
+        // gap at the turn: 20 + 20 - 1 + 0.5 = 39.5.
+        let expected_area = 39.5;
+        let area: f64 = grown.0.iter().map(polygon_area).sum();
+        assert!(
+            (area - expected_area).abs() < 1e-6,
+            "area {} != expected {}",
+            area,
+            expected_area
+        );
+    }
+}